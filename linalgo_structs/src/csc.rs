@@ -0,0 +1,84 @@
+use crate::SquareMatrix;
+
+/// A square matrix stored in compressed sparse column (CSC) format, suitable for matrices that
+/// are mostly empty and too large to hold densely.
+///
+/// Storage mirrors the layout used by established sparse libraries: `p` holds column pointers
+/// (length `size + 1`), `i` holds the row index of each stored entry, and `vals` holds the
+/// entries themselves. Within a column, the entries are sorted by row index, i.e. for column `j`
+/// the entries live at `i[p[j]..p[j + 1]]` and `vals[p[j]..p[j + 1]]`.
+pub struct CscMatrix<T> {
+    size: usize,
+    p: Vec<usize>,
+    i: Vec<usize>,
+    vals: Vec<T>,
+}
+
+impl<T> CscMatrix<T> {
+    /// Returns the size of the matrix.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns a reference to the value at the given position, or `None` if nothing is stored
+    /// there.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        let start = *self.p.get(col)?;
+        let end = *self.p.get(col + 1)?;
+
+        let column_rows = &self.i[start..end];
+        let offset = column_rows.binary_search(&row).ok()?;
+
+        self.vals.get(start + offset)
+    }
+
+    /// Returns an iterator over the stored `(row, &value)` pairs in the given column, in
+    /// ascending row order.
+    pub fn column_entries(&self, col: usize) -> impl Iterator<Item = (usize, &T)> {
+        let start = self.p[col];
+        let end = self.p[col + 1];
+
+        self.i[start..end].iter().copied().zip(&self.vals[start..end])
+    }
+}
+
+impl<T: Clone> From<&SquareMatrix<T>> for CscMatrix<T> {
+    /// Compresses a dense `SquareMatrix`, skipping any `None` cells.
+    fn from(matrix: &SquareMatrix<T>) -> Self {
+        let size = matrix.size();
+
+        let mut p = Vec::with_capacity(size + 1);
+        let mut i = Vec::new();
+        let mut vals = Vec::new();
+
+        p.push(0);
+        for col in 0..size {
+            for row in 0..size {
+                if let Some(entry) = matrix.get(row, col) {
+                    i.push(row);
+                    vals.push(entry.clone());
+                }
+            }
+
+            p.push(i.len());
+        }
+
+        Self { size, p, i, vals }
+    }
+}
+
+impl<T: Clone> From<&CscMatrix<T>> for SquareMatrix<T> {
+    /// Expands a `CscMatrix` back into a dense `SquareMatrix`, filling only the positions that
+    /// were actually stored and leaving the rest `None`.
+    fn from(matrix: &CscMatrix<T>) -> Self {
+        let mut dense: SquareMatrix<T> = SquareMatrix::new(matrix.size);
+
+        for col in 0..matrix.size {
+            for (row, value) in matrix.column_entries(col) {
+                dense.set(row, col, value.clone());
+            }
+        }
+
+        dense
+    }
+}
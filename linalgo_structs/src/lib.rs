@@ -1,4 +1,13 @@
+mod csc;
+mod macros;
+mod ops;
+#[cfg(feature = "proptest")]
+pub mod proptest_support;
+
+pub use csc::CscMatrix;
+
 /// A square matrix with a fixed size
+#[derive(Debug)]
 pub struct SquareMatrix<T> {
     size: usize,
     data: Vec<Vec<Option<T>>>,
@@ -122,9 +131,247 @@ impl<T: Clone> SquareMatrix<T> {
             }
         }
     }
+
+    /// Returns the `(size - 1) x (size - 1)` matrix formed by deleting the given row and column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::SquareMatrix;
+    ///
+    /// let mut matrix: SquareMatrix<i32> = SquareMatrix::new(2);
+    /// matrix.set(0, 0, 1);
+    /// matrix.set(0, 1, 2);
+    /// matrix.set(1, 0, 3);
+    /// matrix.set(1, 1, 4);
+    ///
+    /// let minor = matrix.minor(0, 0);
+    /// assert_eq!(minor.size(), 1);
+    /// assert_eq!(minor.get(0, 0), Some(4).as_ref());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// A 1x1 matrix has no minors, so we panic if `self.size()` is less than 2.
+    pub fn minor(&self, row: usize, col: usize) -> SquareMatrix<T> {
+        if self.size < 2 {
+            panic!(
+                "cannot take a minor of a {}x{} matrix, size must be at least 2",
+                self.size, self.size
+            );
+        }
+
+        let mut minor: SquareMatrix<T> = SquareMatrix::new(self.size - 1);
+
+        let mut minor_row = 0;
+        for r in 0..self.size {
+            if r == row {
+                continue;
+            }
+
+            let mut minor_col = 0;
+            for c in 0..self.size {
+                if c == col {
+                    continue;
+                }
+
+                if let Some(entry) = self.get(r, c) {
+                    minor.set(minor_row, minor_col, entry.clone());
+                }
+
+                minor_col += 1;
+            }
+
+            minor_row += 1;
+        }
+
+        minor
+    }
+
+    /// Returns an iterator over every cell in row-major order, yielding `(row, col, entry)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::matrix;
+    ///
+    /// let m = matrix![1, 2; 3, 4];
+    /// let cells: Vec<_> = m.iter_indexed().collect();
+    ///
+    /// assert_eq!(cells[1], (0, 1, Some(2).as_ref()));
+    /// ```
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, usize, Option<&T>)> {
+        self.data.iter().enumerate().flat_map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(c, entry)| (r, c, entry.as_ref()))
+        })
+    }
+
+    /// Returns a mutable iterator over every cell in row-major order, yielding
+    /// `(row, col, entry)`.
+    pub fn iter_indexed_mut(&mut self) -> impl Iterator<Item = (usize, usize, Option<&mut T>)> {
+        self.data.iter_mut().enumerate().flat_map(|(r, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(c, entry)| (r, c, entry.as_mut()))
+        })
+    }
+
+    /// Returns an iterator over the rows of the matrix, each yielded as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::matrix;
+    ///
+    /// let m = matrix![1, 2; 3, 4];
+    /// let first_row: Vec<_> = m.rows().next().unwrap().to_vec();
+    ///
+    /// assert_eq!(first_row, vec![Some(1), Some(2)]);
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = &[Option<T>]> {
+        self.data.iter().map(|row| row.as_slice())
+    }
+
+    /// Returns a mutable iterator over the rows of the matrix, each yielded as a slice.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [Option<T>]> {
+        self.data.iter_mut().map(|row| row.as_mut_slice())
+    }
+
+    /// Returns an iterator over the columns of the matrix, each yielded as an iterator over the
+    /// entries of that column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::matrix;
+    ///
+    /// let m = matrix![1, 2; 3, 4];
+    /// let first_column: Vec<_> = m.columns().next().unwrap().collect();
+    ///
+    /// assert_eq!(first_column, vec![Some(1).as_ref(), Some(3).as_ref()]);
+    /// ```
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = Option<&T>>> {
+        (0..self.size).map(move |c| (0..self.size).map(move |r| self.data[r][c].as_ref()))
+    }
+
+    /// Returns a new matrix with rows and columns swapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::matrix;
+    ///
+    /// let m = matrix![1, 2; 3, 4];
+    /// let t = m.transpose();
+    ///
+    /// assert_eq!(t.get(0, 1), Some(3).as_ref());
+    /// assert_eq!(t.get(1, 0), Some(2).as_ref());
+    /// ```
+    pub fn transpose(&self) -> SquareMatrix<T> {
+        let mut result = SquareMatrix::new(self.size);
+
+        for (r, c, entry) in self.iter_indexed() {
+            if let Some(value) = entry {
+                result.set(c, r, value.clone());
+            }
+        }
+
+        result
+    }
+
+    /// Clones `value` into every position on the main diagonal. Does not consume `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::SquareMatrix;
+    ///
+    /// let mut matrix: SquareMatrix<i32> = SquareMatrix::new(3);
+    /// matrix.set_diagonal(&1);
+    ///
+    /// assert_eq!(matrix.get(1, 1), Some(1).as_ref());
+    /// assert_eq!(matrix.get(0, 1), None);
+    /// ```
+    pub fn set_diagonal(&mut self, value: &T) {
+        for i in 0..self.size {
+            self.set(i, i, value.clone());
+        }
+    }
+}
+
+impl<T: num_traits::Num + Clone> SquareMatrix<T> {
+    /// Computes the determinant via Laplace cofactor expansion along the first row. Returns
+    /// `None` if any entry needed by the expansion is itself `None`, respecting the sparse
+    /// `Option` storage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::SquareMatrix;
+    ///
+    /// let mut matrix: SquareMatrix<i32> = SquareMatrix::new(2);
+    /// matrix.set(0, 0, 1);
+    /// matrix.set(0, 1, 2);
+    /// matrix.set(1, 0, 3);
+    /// matrix.set(1, 1, 4);
+    ///
+    /// assert_eq!(matrix.determinant(), Some(1 * 4 - 2 * 3));
+    /// ```
+    pub fn determinant(&self) -> Option<T> {
+        if self.size == 1 {
+            return self.get(0, 0).cloned();
+        }
+
+        if self.size == 2 {
+            let a = self.get(0, 0)?.clone();
+            let b = self.get(0, 1)?.clone();
+            let c = self.get(1, 0)?.clone();
+            let d = self.get(1, 1)?.clone();
+
+            return Some(a * d - b * c);
+        }
+
+        let mut det = T::zero();
+        for j in 0..self.size {
+            let entry = self.get(0, j)?.clone();
+            let cofactor = self.minor(0, j).determinant()?;
+
+            det = if j % 2 == 0 {
+                det + entry * cofactor
+            } else {
+                det - entry * cofactor
+            };
+        }
+
+        Some(det)
+    }
+
+    /// Returns the identity matrix of the given size, with `T::one()` on the main diagonal and
+    /// `T::zero()` everywhere else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::SquareMatrix;
+    ///
+    /// let identity: SquareMatrix<i32> = SquareMatrix::identity(3);
+    ///
+    /// assert_eq!(identity.get(1, 1), Some(1).as_ref());
+    /// assert_eq!(identity.get(0, 1), Some(0).as_ref());
+    /// ```
+    pub fn identity(size: usize) -> Self {
+        let mut matrix = SquareMatrix::new(size);
+        matrix.set_all_to(&T::zero());
+        matrix.set_diagonal(&T::one());
+
+        matrix
+    }
 }
 
 /// A vector with a fixed size
+#[derive(Debug)]
 pub struct Vector<T> {
     size: usize,
     data: Vec<Option<T>>,
@@ -235,4 +482,31 @@ impl<T: Clone> Vector<T> {
             *entry = Some(value.clone());
         }
     }
+
+    /// Returns an iterator over every entry, yielding `(position, entry)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linalgo_structs::vector;
+    ///
+    /// let v = vector![1, 2, 3];
+    /// let entries: Vec<_> = v.iter_indexed().collect();
+    ///
+    /// assert_eq!(entries[1], (1, Some(2).as_ref()));
+    /// ```
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, Option<&T>)> {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| (i, entry.as_ref()))
+    }
+
+    /// Returns a mutable iterator over every entry, yielding `(position, entry)`.
+    pub fn iter_indexed_mut(&mut self) -> impl Iterator<Item = (usize, Option<&mut T>)> {
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(|(i, entry)| (i, entry.as_mut()))
+    }
 }
@@ -0,0 +1,66 @@
+/// Builds a [`SquareMatrix`](crate::SquareMatrix) from a row-major literal, e.g.
+/// `matrix![1, 2; 3, 4]`.
+///
+/// # Examples
+///
+/// ```
+/// use linalgo_structs::matrix;
+///
+/// let m = matrix![1, 2; 3, 4];
+/// assert_eq!(m.get(0, 1), Some(2).as_ref());
+/// ```
+///
+/// # Panics
+///
+/// Panics if the rows don't all have the same length, or if the matrix isn't square (the row
+/// count must equal the column count).
+#[macro_export]
+macro_rules! matrix {
+    ( $( $( $value:expr ),+ );+ $(;)? ) => {{
+        let rows: &[&[_]] = &[ $( &[ $( $value ),+ ] ),+ ];
+        let size = rows.len();
+
+        for row in rows {
+            if row.len() != size {
+                panic!(
+                    "matrix! requires a square matrix with equal-length rows, got {} rows but a row of length {}",
+                    size,
+                    row.len()
+                );
+            }
+        }
+
+        let mut matrix = $crate::SquareMatrix::new(size);
+        for (r, row) in rows.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                matrix.set(r, c, value.clone());
+            }
+        }
+
+        matrix
+    }};
+}
+
+/// Builds a [`Vector`](crate::Vector) from a literal, e.g. `vector![1, 2, 3]`.
+///
+/// # Examples
+///
+/// ```
+/// use linalgo_structs::vector;
+///
+/// let v = vector![1, 2, 3];
+/// assert_eq!(v.get(1), Some(2).as_ref());
+/// ```
+#[macro_export]
+macro_rules! vector {
+    ( $( $value:expr ),+ $(,)? ) => {{
+        let values = [ $( $value ),+ ];
+
+        let mut vector = $crate::Vector::new(values.len());
+        for (i, value) in values.iter().enumerate() {
+            vector.set(i, value.clone());
+        }
+
+        vector
+    }};
+}
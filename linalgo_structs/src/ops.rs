@@ -0,0 +1,155 @@
+use std::ops::{Add, Mul, Sub};
+
+use num_traits::Num;
+
+use crate::{SquareMatrix, Vector};
+
+impl<T: Num + Clone> Add for SquareMatrix<T> {
+    type Output = SquareMatrix<T>;
+
+    /// Adds two matrices element-wise. If either operand is `None` at a position, the result is
+    /// `None` there too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrices don't have the same size.
+    fn add(self, rhs: Self) -> Self::Output {
+        assert_eq!(
+            self.size(),
+            rhs.size(),
+            "cannot add matrices of different sizes ({} vs {})",
+            self.size(),
+            rhs.size()
+        );
+
+        let mut result = SquareMatrix::new(self.size());
+        for (r, c, entry) in self.iter_indexed() {
+            if let (Some(a), Some(b)) = (entry, rhs.get(r, c)) {
+                result.set(r, c, a.clone() + b.clone());
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Num + Clone> Sub for SquareMatrix<T> {
+    type Output = SquareMatrix<T>;
+
+    /// Subtracts two matrices element-wise. If either operand is `None` at a position, the
+    /// result is `None` there too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrices don't have the same size.
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert_eq!(
+            self.size(),
+            rhs.size(),
+            "cannot subtract matrices of different sizes ({} vs {})",
+            self.size(),
+            rhs.size()
+        );
+
+        let mut result = SquareMatrix::new(self.size());
+        for (r, c, entry) in self.iter_indexed() {
+            if let (Some(a), Some(b)) = (entry, rhs.get(r, c)) {
+                result.set(r, c, a.clone() - b.clone());
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Num + Clone> Mul for SquareMatrix<T> {
+    type Output = SquareMatrix<T>;
+
+    /// Multiplies two matrices, computing `C[i][j] = sum_k A[i][k] * B[k][j]`. `None` is not
+    /// treated as the additive identity: if any factor needed for an output cell is missing, that
+    /// cell is `None` in the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrices don't have the same size.
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert_eq!(
+            self.size(),
+            rhs.size(),
+            "cannot multiply matrices of different sizes ({} vs {})",
+            self.size(),
+            rhs.size()
+        );
+
+        let size = self.size();
+        let mut result = SquareMatrix::new(size);
+
+        for i in 0..size {
+            for j in 0..size {
+                let mut sum = Some(T::zero());
+
+                for k in 0..size {
+                    sum = match (sum, self.get(i, k), rhs.get(k, j)) {
+                        (Some(acc), Some(a), Some(b)) => Some(acc + a.clone() * b.clone()),
+                        _ => None,
+                    };
+
+                    if sum.is_none() {
+                        break;
+                    }
+                }
+
+                if let Some(value) = sum {
+                    result.set(i, j, value);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Num + Clone> Mul<Vector<T>> for SquareMatrix<T> {
+    type Output = Vector<T>;
+
+    /// Multiplies the matrix by a vector, computing `out[i] = sum_k self[i][k] * rhs[k]`. `None`
+    /// is not treated as the additive identity: if any factor needed for an output entry is
+    /// missing, that entry is `None` in the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix's size doesn't match the vector's size.
+    fn mul(self, rhs: Vector<T>) -> Self::Output {
+        assert_eq!(
+            self.size(),
+            rhs.size(),
+            "cannot multiply a matrix and a vector of different sizes ({} vs {})",
+            self.size(),
+            rhs.size()
+        );
+
+        let size = self.size();
+        let mut result = Vector::new(size);
+
+        for i in 0..size {
+            let mut sum = Some(T::zero());
+
+            for k in 0..size {
+                sum = match (sum, self.get(i, k), rhs.get(k)) {
+                    (Some(acc), Some(a), Some(b)) => Some(acc + a.clone() * b.clone()),
+                    _ => None,
+                };
+
+                if sum.is_none() {
+                    break;
+                }
+            }
+
+            if let Some(value) = sum {
+                result.set(i, value);
+            }
+        }
+
+        result
+    }
+}
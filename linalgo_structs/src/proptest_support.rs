@@ -0,0 +1,72 @@
+//! `proptest` strategies for generating random [`SquareMatrix`]/[`Vector`] values. Enabled by the
+//! `proptest` feature.
+
+use std::fmt::Debug;
+use std::ops::Range;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use crate::{SquareMatrix, Vector};
+
+/// Returns a [`Strategy`] that generates a [`SquareMatrix`] by first picking a dimension from
+/// `size_range`, then filling every cell with a value drawn from `value_strategy`.
+///
+/// Shrinking comes from `prop_flat_map`: proptest first shrinks the entries toward
+/// `value_strategy`'s simpler values, and only once they can't shrink further does it try a
+/// smaller dimension, which draws a fresh set of entries. Dimension shrinking does not keep the
+/// entries that had already been shrunk, so as is typical for matrix strategies proptest may take
+/// several passes to reach a minimal failing case.
+///
+/// # Panics
+///
+/// Panics (when the strategy is evaluated) if `size_range` can produce `0`, since
+/// `SquareMatrix::new` doesn't allow zero-dimensional matrices.
+pub fn matrix_strategy<T, S>(
+    size_range: Range<usize>,
+    value_strategy: S,
+) -> impl Strategy<Value = SquareMatrix<T>>
+where
+    T: Clone + Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    size_range.prop_flat_map(move |size| {
+        vec(value_strategy.clone(), size * size).prop_map(move |values| {
+            let mut matrix = SquareMatrix::new(size);
+            for (index, value) in values.into_iter().enumerate() {
+                matrix.set(index / size, index % size, value);
+            }
+
+            matrix
+        })
+    })
+}
+
+/// Returns a [`Strategy`] that generates a [`Vector`] by first picking a dimension from
+/// `size_range`, then filling every entry with a value drawn from `value_strategy`.
+///
+/// Shrinking behaves the same way as [`matrix_strategy`]: entries first, then dimension.
+///
+/// # Panics
+///
+/// Panics (when the strategy is evaluated) if `size_range` can produce `0`, since `Vector::new`
+/// doesn't allow zero-dimensional vectors.
+pub fn vector_strategy<T, S>(
+    size_range: Range<usize>,
+    value_strategy: S,
+) -> impl Strategy<Value = Vector<T>>
+where
+    T: Clone + Debug,
+    S: Strategy<Value = T> + Clone,
+{
+    size_range.prop_flat_map(move |size| {
+        vec(value_strategy.clone(), size).prop_map(move |values| {
+            let mut vector = Vector::new(size);
+            for (index, value) in values.into_iter().enumerate() {
+                vector.set(index, value);
+            }
+
+            vector
+        })
+    })
+}